@@ -0,0 +1,271 @@
+//! Proc-macro companion to the `envgrd` crate: `#[derive(FromEnv)]`.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::{parse_macro_input, Data, DeriveInput, Fields, GenericArgument, Lit, PathArguments, Type};
+
+#[proc_macro_derive(FromEnv, attributes(env))]
+pub fn derive_from_env(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let name = &input.ident;
+
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return syn::Error::new_spanned(&input, "FromEnv only supports structs with named fields")
+                    .to_compile_error()
+                    .into()
+            }
+        },
+        _ => {
+            return syn::Error::new_spanned(&input, "FromEnv can only be derived for structs")
+                .to_compile_error()
+                .into()
+        }
+    };
+
+    let mut bindings = Vec::new();
+    let mut assignments = Vec::new();
+
+    for field in fields {
+        let field_ident = field.ident.as_ref().expect("named field");
+        let attrs = match FieldAttrs::parse(field) {
+            Ok(attrs) => attrs,
+            Err(err) => return err.to_compile_error().into(),
+        };
+        let var_name = attrs
+            .rename
+            .unwrap_or_else(|| screaming_snake_case(&field_ident.to_string()));
+        let field_str = field_ident.to_string();
+        let binding_ident = format_ident!("__envgrd_{}", field_ident);
+
+        let kind = match FieldKind::classify(&field.ty, attrs.delimiter.as_deref(), field) {
+            Ok(kind) => kind,
+            Err(err) => return err.to_compile_error().into(),
+        };
+
+        if attrs.secret && inner_type_of(kind.parse_type(), "Secret").is_none() {
+            return syn::Error::new_spanned(
+                field,
+                "#[env(secret)] fields must be typed as `Secret<T>` (or `Option<Secret<T>>`)",
+            )
+            .to_compile_error()
+            .into();
+        }
+
+        let fetch = kind.gen_fetch(&binding_ident, &var_name, &field_str, attrs.default.as_deref());
+        bindings.push(fetch);
+        assignments.push(kind.gen_assignment(field_ident, &binding_ident));
+    }
+
+    let expanded = quote! {
+        impl ::envgrd::FromEnv for #name {
+            fn from_env() -> ::std::result::Result<Self, ::envgrd::FromEnvError> {
+                let mut errors: ::std::vec::Vec<::envgrd::FieldError> = ::std::vec::Vec::new();
+                #(#bindings)*
+                if !errors.is_empty() {
+                    return ::std::result::Result::Err(::envgrd::FromEnvError::new(errors));
+                }
+                ::std::result::Result::Ok(#name {
+                    #(#assignments),*
+                })
+            }
+        }
+    };
+
+    expanded.into()
+}
+
+/// How a single field's value is produced from its environment variable.
+enum FieldKind<'a> {
+    /// `Option<T>`: missing is `None`, not an error.
+    Optional(&'a Type),
+    /// `Vec<T>` bound via `#[env(delimiter = "...")]`.
+    List(&'a Type, String),
+    /// Any other `FromStr` type, optionally with `#[env(default = "...")]`.
+    Plain(&'a Type),
+}
+
+impl<'a> FieldKind<'a> {
+    fn classify(ty: &'a Type, delimiter: Option<&str>, field: &syn::Field) -> syn::Result<Self> {
+        if let Some(inner) = inner_type_of(ty, "Option") {
+            return Ok(FieldKind::Optional(inner));
+        }
+        if let Some(inner) = inner_type_of(ty, "Vec") {
+            return match delimiter {
+                Some(delimiter) => Ok(FieldKind::List(inner, delimiter.to_string())),
+                None => Err(syn::Error::new_spanned(
+                    field,
+                    "Vec fields require #[env(delimiter = \"...\")]",
+                )),
+            };
+        }
+        Ok(FieldKind::Plain(ty))
+    }
+
+    fn gen_fetch(
+        &self,
+        binding: &syn::Ident,
+        var_name: &str,
+        field_str: &str,
+        default: Option<&str>,
+    ) -> TokenStream2 {
+        let missing_push = quote! {
+            errors.push(::envgrd::FieldError {
+                field: #field_str,
+                var: #var_name.to_string(),
+                kind: ::envgrd::FieldErrorKind::Missing,
+            });
+        };
+        let invalid_push = |err: TokenStream2| {
+            quote! {
+                errors.push(::envgrd::FieldError {
+                    field: #field_str,
+                    var: #var_name.to_string(),
+                    kind: ::envgrd::FieldErrorKind::Invalid(#err.to_string()),
+                });
+            }
+        };
+
+        match self {
+            FieldKind::Optional(inner) => {
+                let invalid = invalid_push(quote! { err });
+                quote! {
+                    let #binding: Option<#inner> = match ::std::env::var(#var_name) {
+                        Ok(raw) => match raw.parse::<#inner>() {
+                            Ok(value) => Some(value),
+                            Err(err) => { #invalid None }
+                        },
+                        Err(_) => None,
+                    };
+                }
+            }
+            FieldKind::List(inner, delimiter) => {
+                let invalid = invalid_push(quote! { err });
+                quote! {
+                    let #binding: Option<::std::vec::Vec<#inner>> = match ::std::env::var(#var_name) {
+                        Ok(raw) => match ::envgrd::parse_delimited::<#inner>(&raw, #delimiter) {
+                            Ok(values) => Some(values),
+                            Err(err) => { #invalid None }
+                        },
+                        Err(_) => { #missing_push None }
+                    };
+                }
+            }
+            FieldKind::Plain(ty) => {
+                let invalid = invalid_push(quote! { err });
+                match default {
+                    Some(default) => quote! {
+                        let #binding: Option<#ty> = match ::std::env::var(#var_name) {
+                            Ok(raw) => match raw.parse::<#ty>() {
+                                Ok(value) => Some(value),
+                                Err(err) => { #invalid None }
+                            },
+                            Err(_) => match #default.parse::<#ty>() {
+                                Ok(value) => Some(value),
+                                Err(err) => { #invalid None }
+                            },
+                        };
+                    },
+                    None => quote! {
+                        let #binding: Option<#ty> = match ::std::env::var(#var_name) {
+                            Ok(raw) => match raw.parse::<#ty>() {
+                                Ok(value) => Some(value),
+                                Err(err) => { #invalid None }
+                            },
+                            Err(_) => { #missing_push None }
+                        };
+                    },
+                }
+            }
+        }
+    }
+
+    fn gen_assignment(&self, field_ident: &syn::Ident, binding: &syn::Ident) -> TokenStream2 {
+        match self {
+            FieldKind::Optional(_) => quote! { #field_ident: #binding },
+            FieldKind::List(..) | FieldKind::Plain(_) => quote! { #field_ident: #binding.unwrap() },
+        }
+    }
+
+    /// The type actually parsed via `FromStr` for this field, used to
+    /// validate `#[env(secret)]` placement.
+    fn parse_type(&self) -> &Type {
+        match self {
+            FieldKind::Optional(ty) | FieldKind::List(ty, _) | FieldKind::Plain(ty) => ty,
+        }
+    }
+}
+
+struct FieldAttrs {
+    rename: Option<String>,
+    default: Option<String>,
+    delimiter: Option<String>,
+    secret: bool,
+}
+
+impl FieldAttrs {
+    fn parse(field: &syn::Field) -> syn::Result<Self> {
+        let mut rename = None;
+        let mut default = None;
+        let mut delimiter = None;
+        let mut secret = false;
+        for attr in &field.attrs {
+            if !attr.path().is_ident("env") {
+                continue;
+            }
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("rename") {
+                    rename = Some(parse_str_value(&meta)?);
+                } else if meta.path.is_ident("default") {
+                    default = Some(parse_str_value(&meta)?);
+                } else if meta.path.is_ident("delimiter") {
+                    delimiter = Some(parse_str_value(&meta)?);
+                } else if meta.path.is_ident("secret") {
+                    secret = true;
+                } else {
+                    return Err(meta.error("unsupported envgrd field attribute"));
+                }
+                Ok(())
+            })?;
+        }
+        Ok(FieldAttrs {
+            rename,
+            default,
+            delimiter,
+            secret,
+        })
+    }
+}
+
+fn parse_str_value(meta: &syn::meta::ParseNestedMeta) -> syn::Result<String> {
+    let value = meta.value()?;
+    let lit: Lit = value.parse()?;
+    match lit {
+        Lit::Str(s) => Ok(s.value()),
+        _ => Err(meta.error("expected a string literal")),
+    }
+}
+
+fn inner_type_of<'a>(ty: &'a Type, wrapper: &str) -> Option<&'a Type> {
+    let Type::Path(type_path) = ty else {
+        return None;
+    };
+    let segment = type_path.path.segments.last()?;
+    if segment.ident != wrapper {
+        return None;
+    }
+    let PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return None;
+    };
+    args.args.iter().find_map(|arg| match arg {
+        GenericArgument::Type(ty) => Some(ty),
+        _ => None,
+    })
+}
+
+fn screaming_snake_case(field: &str) -> String {
+    field.to_uppercase()
+}
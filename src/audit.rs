@@ -0,0 +1,205 @@
+use std::fmt;
+
+/// Declares one environment variable an application expects at startup.
+///
+/// Build specs with [`VarSpec::new`], then pass them to [`audit`] to get a
+/// structured report instead of letting a missing variable silently turn
+/// into an empty string via `unwrap_or_default()`.
+pub struct VarSpec {
+    name: &'static str,
+    required: bool,
+    parse: fn(&str) -> Result<(), String>,
+}
+
+impl VarSpec {
+    /// A required variable whose value is accepted as-is (no parsing).
+    pub fn new(name: &'static str) -> Self {
+        VarSpec {
+            name,
+            required: true,
+            parse: |_| Ok(()),
+        }
+    }
+
+    /// Mark this variable as optional: its absence is reported but does
+    /// not make the report fail.
+    pub fn optional(mut self) -> Self {
+        self.required = false;
+        self
+    }
+
+    /// Require the value to parse as `T`, reporting the `FromStr` error
+    /// text if it doesn't.
+    pub fn parse_as<T>(mut self) -> Self
+    where
+        T: std::str::FromStr,
+        T::Err: fmt::Display,
+    {
+        self.parse = |raw| raw.parse::<T>().map(|_| ()).map_err(|err| err.to_string());
+        self
+    }
+}
+
+/// Whether a declared variable was present, missing, or unparseable.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VarStatus {
+    /// Set and, if a parser was declared, parsed successfully.
+    Present,
+    /// Not set in the process environment.
+    Missing,
+    /// Set, but rejected by the declared parser.
+    Invalid(String),
+}
+
+/// The audit outcome for a single declared variable.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuditEntry {
+    pub name: &'static str,
+    pub required: bool,
+    pub status: VarStatus,
+}
+
+impl AuditEntry {
+    /// Whether this entry should block startup: required but missing or
+    /// invalid.
+    pub fn is_failure(&self) -> bool {
+        self.required && !matches!(self.status, VarStatus::Present)
+    }
+}
+
+/// The result of auditing a set of [`VarSpec`]s against the process
+/// environment.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuditReport {
+    entries: Vec<AuditEntry>,
+}
+
+impl AuditReport {
+    /// All audited entries, in the order the specs were given.
+    pub fn entries(&self) -> &[AuditEntry] {
+        &self.entries
+    }
+
+    /// `true` if every required variable is present and valid.
+    pub fn is_ok(&self) -> bool {
+        !self.entries.iter().any(AuditEntry::is_failure)
+    }
+
+    /// The entries that are missing or invalid and required.
+    pub fn failures(&self) -> impl Iterator<Item = &AuditEntry> {
+        self.entries.iter().filter(|entry| entry.is_failure())
+    }
+
+    /// Print the report to stderr and exit the process with status 1 if
+    /// any required variable is missing or invalid.
+    ///
+    /// Intended for a fail-fast startup check in `main`, in place of
+    /// letting a missing variable silently become an empty string.
+    pub fn exit_on_failure(&self) {
+        if self.is_ok() {
+            return;
+        }
+        eprintln!("{self}");
+        std::process::exit(1);
+    }
+}
+
+impl fmt::Display for AuditReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "environment audit:")?;
+        for entry in &self.entries {
+            let marker = match &entry.status {
+                VarStatus::Present => "ok".to_string(),
+                VarStatus::Missing if entry.required => "MISSING".to_string(),
+                VarStatus::Missing => "missing (optional)".to_string(),
+                VarStatus::Invalid(reason) => format!("INVALID: {reason}"),
+            };
+            writeln!(f, "  {} [{marker}]", entry.name)?;
+        }
+        Ok(())
+    }
+}
+
+/// Audit `specs` against the current process environment.
+pub fn audit(specs: &[VarSpec]) -> AuditReport {
+    let entries = specs
+        .iter()
+        .map(|spec| {
+            let status = match std::env::var(spec.name) {
+                Ok(raw) => match (spec.parse)(&raw) {
+                    Ok(()) => VarStatus::Present,
+                    Err(reason) => VarStatus::Invalid(reason),
+                },
+                Err(_) => VarStatus::Missing,
+            };
+            AuditEntry {
+                name: spec.name,
+                required: spec.required,
+                status,
+            }
+        })
+        .collect();
+    AuditReport { entries }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+    use std::sync::Mutex;
+
+    static ENV_LOCK: Mutex<()> = Mutex::new(());
+
+    #[test]
+    fn reports_present_missing_and_invalid() {
+        let _guard = ENV_LOCK.lock().unwrap();
+        env::set_var("AUDIT_PRESENT", "value");
+        env::remove_var("AUDIT_MISSING");
+        env::set_var("AUDIT_INVALID", "not-a-number");
+
+        let report = audit(&[
+            VarSpec::new("AUDIT_PRESENT"),
+            VarSpec::new("AUDIT_MISSING"),
+            VarSpec::new("AUDIT_INVALID").parse_as::<u16>(),
+        ]);
+
+        assert!(!report.is_ok());
+        assert_eq!(report.entries()[0].status, VarStatus::Present);
+        assert_eq!(report.entries()[1].status, VarStatus::Missing);
+        assert!(matches!(report.entries()[2].status, VarStatus::Invalid(_)));
+        assert_eq!(report.failures().count(), 2);
+
+        env::remove_var("AUDIT_PRESENT");
+        env::remove_var("AUDIT_INVALID");
+    }
+
+    #[test]
+    fn optional_missing_does_not_fail_the_report() {
+        let _guard = ENV_LOCK.lock().unwrap();
+        env::remove_var("AUDIT_OPTIONAL");
+
+        let report = audit(&[VarSpec::new("AUDIT_OPTIONAL").optional()]);
+        assert!(report.is_ok());
+    }
+
+    #[test]
+    fn display_prints_every_entry_even_after_an_invalid_one() {
+        let _guard = ENV_LOCK.lock().unwrap();
+        env::remove_var("ZZ_A");
+        env::set_var("ZZ_B", "not-a-number");
+        env::remove_var("ZZ_C");
+
+        let report = audit(&[
+            VarSpec::new("ZZ_A"),
+            VarSpec::new("ZZ_B").parse_as::<u16>(),
+            VarSpec::new("ZZ_C"),
+        ]);
+
+        let rendered = report.to_string();
+        assert!(rendered.contains("ZZ_A"));
+        assert!(rendered.contains("ZZ_B"));
+        assert!(rendered.contains("ZZ_C"));
+
+        env::remove_var("ZZ_B");
+    }
+}
@@ -0,0 +1,39 @@
+use std::fmt;
+use std::io;
+use std::path::PathBuf;
+
+/// Errors produced while parsing or loading a `.env`-style file.
+#[derive(Debug)]
+pub enum ParseError {
+    /// The file could not be read from disk.
+    Io { path: PathBuf, source: io::Error },
+    /// A line could not be parsed as `KEY=VALUE`, a comment, or a blank line.
+    MalformedLine { line: usize, text: String },
+    /// A quoted value was never closed.
+    UnterminatedQuote { line: usize },
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::Io { path, source } => {
+                write!(f, "failed to read {}: {}", path.display(), source)
+            }
+            ParseError::MalformedLine { line, text } => {
+                write!(f, "line {line}: malformed entry: {text:?}")
+            }
+            ParseError::UnterminatedQuote { line } => {
+                write!(f, "line {line}: unterminated quoted value")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ParseError::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,80 @@
+use std::fmt;
+
+/// Implemented by config structs whose fields are bound to environment
+/// variables, typically via `#[derive(FromEnv)]`.
+pub trait FromEnv: Sized {
+    /// Read every field from its bound environment variable.
+    ///
+    /// All missing or unparseable fields are collected and reported
+    /// together in a single [`FromEnvError`] rather than failing on the
+    /// first one encountered.
+    fn from_env() -> Result<Self, FromEnvError>;
+}
+
+/// One field that could not be bound from the environment.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldError {
+    /// Name of the struct field.
+    pub field: &'static str,
+    /// Name of the environment variable it is bound to.
+    pub var: String,
+    /// What went wrong.
+    pub kind: FieldErrorKind,
+}
+
+/// The specific way a field failed to bind.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FieldErrorKind {
+    /// The variable was not set and the field has no default.
+    Missing,
+    /// The variable's value (or, when it is unset, the field's
+    /// `#[env(default)]` value) could not be parsed into the field's type.
+    Invalid(String),
+}
+
+impl fmt::Display for FieldError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            FieldErrorKind::Missing => {
+                write!(f, "{} ({}): not set", self.field, self.var)
+            }
+            FieldErrorKind::Invalid(reason) => {
+                write!(f, "{} ({}): {}", self.field, self.var, reason)
+            }
+        }
+    }
+}
+
+/// All the field errors encountered while binding a [`FromEnv`] struct.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FromEnvError {
+    errors: Vec<FieldError>,
+}
+
+impl FromEnvError {
+    /// Build an error from the fields that failed to bind.
+    ///
+    /// Panics if `errors` is empty, since an error with no causes would
+    /// be misleading to callers.
+    pub fn new(errors: Vec<FieldError>) -> Self {
+        assert!(!errors.is_empty(), "FromEnvError requires at least one FieldError");
+        FromEnvError { errors }
+    }
+
+    /// The individual field failures, in field-declaration order.
+    pub fn errors(&self) -> &[FieldError] {
+        &self.errors
+    }
+}
+
+impl fmt::Display for FromEnvError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "failed to bind {} field(s) from the environment:", self.errors.len())?;
+        for err in &self.errors {
+            writeln!(f, "  - {err}")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for FromEnvError {}
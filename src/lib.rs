@@ -0,0 +1,21 @@
+//! Layered `.env` loading, typed config binding, and environment variable
+//! auditing.
+
+mod audit;
+mod error;
+mod from_env;
+mod list;
+mod loader;
+mod secret;
+mod store;
+
+pub use audit::{audit, AuditEntry, AuditReport, VarSpec, VarStatus};
+pub use error::ParseError;
+pub use from_env::{FieldError, FieldErrorKind, FromEnv, FromEnvError};
+pub use list::{parse_delimited, ListParseError};
+pub use loader::{load, load_from, parse_str, EnvGrd};
+pub use secret::Secret;
+pub use store::EnvStore;
+
+#[cfg(feature = "derive")]
+pub use envgrd_derive::FromEnv;
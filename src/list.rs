@@ -0,0 +1,79 @@
+use std::fmt;
+use std::str::FromStr;
+
+/// One element of a delimited list could not be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ListParseError {
+    /// Zero-based position of the offending element.
+    pub index: usize,
+    /// The exact (trimmed) token that was rejected.
+    pub token: String,
+    /// The underlying `FromStr` error, as text.
+    pub reason: String,
+}
+
+impl fmt::Display for ListParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "element {} ({:?}): {}", self.index, self.token, self.reason)
+    }
+}
+
+impl std::error::Error for ListParseError {}
+
+/// Split `raw` on `delimiter`, trim whitespace from each piece, and parse
+/// every element through `FromStr`.
+///
+/// An empty (or all-whitespace) `raw` string yields an empty vector.
+/// The first element that fails to parse is reported with its index and
+/// the exact token that was rejected.
+pub fn parse_delimited<T>(raw: &str, delimiter: &str) -> Result<Vec<T>, ListParseError>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    let raw = raw.trim();
+    if raw.is_empty() {
+        return Ok(Vec::new());
+    }
+    raw.split(delimiter)
+        .enumerate()
+        .map(|(index, token)| {
+            let token = token.trim();
+            token.parse::<T>().map_err(|err| ListParseError {
+                index,
+                token: token.to_string(),
+                reason: err.to_string(),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn splits_and_trims_elements() {
+        let values: Vec<String> = parse_delimited("a, b ,c", ",").unwrap();
+        assert_eq!(values, vec!["a", "b", "c"]);
+    }
+
+    #[test]
+    fn parses_each_element() {
+        let values: Vec<u16> = parse_delimited("1,2,3", ",").unwrap();
+        assert_eq!(values, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn empty_input_yields_empty_vec() {
+        let values: Vec<u16> = parse_delimited("  ", ",").unwrap();
+        assert!(values.is_empty());
+    }
+
+    #[test]
+    fn reports_offending_index_and_token() {
+        let err = parse_delimited::<u16>("1,bogus,3", ",").unwrap_err();
+        assert_eq!(err.index, 1);
+        assert_eq!(err.token, "bogus");
+    }
+}
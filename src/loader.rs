@@ -0,0 +1,276 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::error::ParseError;
+
+/// Parse the contents of a `.env`-style file into an ordered list of
+/// `(key, value)` pairs.
+///
+/// Supports `#` comments, an optional leading `export `, single- and
+/// double-quoted values (with `\n`, `\t`, `\\` and matching-quote escapes
+/// inside double quotes), blank lines, CRLF line endings, and trailing
+/// whitespace. Lines that are neither blank, a comment, nor `KEY=VALUE`
+/// are reported as [`ParseError::MalformedLine`] with their 1-based line
+/// number.
+pub fn parse_str(input: &str) -> Result<Vec<(String, String)>, ParseError> {
+    let mut entries = Vec::new();
+    for (idx, raw_line) in input.lines().enumerate() {
+        let line_no = idx + 1;
+        let line = raw_line.trim_end_matches('\r').trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let line = line.strip_prefix("export ").unwrap_or(line).trim_start();
+
+        let eq = line.find('=').ok_or_else(|| ParseError::MalformedLine {
+            line: line_no,
+            text: raw_line.to_string(),
+        })?;
+        let key = line[..eq].trim();
+        if key.is_empty() || !is_valid_key(key) {
+            return Err(ParseError::MalformedLine {
+                line: line_no,
+                text: raw_line.to_string(),
+            });
+        }
+        let raw_value = line[eq + 1..].trim();
+        let value = unquote(raw_value, line_no)?;
+        entries.push((key.to_string(), value));
+    }
+    Ok(entries)
+}
+
+pub(crate) fn is_valid_key(key: &str) -> bool {
+    let mut chars = key.chars();
+    match chars.next() {
+        Some(c) if c.is_ascii_alphabetic() || c == '_' => {}
+        _ => return false,
+    }
+    chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+pub(crate) fn unquote(value: &str, line_no: usize) -> Result<String, ParseError> {
+    let bytes = value.as_bytes();
+    if bytes.len() >= 2 && (bytes[0] == b'"' || bytes[0] == b'\'') {
+        let quote = bytes[0];
+        if bytes[bytes.len() - 1] != quote {
+            return Err(ParseError::UnterminatedQuote { line: line_no });
+        }
+        let inner = &value[1..value.len() - 1];
+        if quote == b'\'' {
+            // Single quotes are taken literally, no escape processing.
+            return Ok(inner.to_string());
+        }
+        let mut out = String::with_capacity(inner.len());
+        let mut chars = inner.chars();
+        while let Some(c) = chars.next() {
+            if c == '\\' {
+                match chars.next() {
+                    Some('n') => out.push('\n'),
+                    Some('t') => out.push('\t'),
+                    Some('"') => out.push('"'),
+                    Some('\\') => out.push('\\'),
+                    Some(other) => {
+                        out.push('\\');
+                        out.push(other);
+                    }
+                    None => out.push('\\'),
+                }
+            } else {
+                out.push(c);
+            }
+        }
+        Ok(out)
+    } else {
+        Ok(value.to_string())
+    }
+}
+
+pub(crate) fn read_to_string(path: &Path) -> Result<String, ParseError> {
+    fs::read_to_string(path).map_err(|source| ParseError::Io {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+/// Apply parsed entries to the process environment, leaving any variable
+/// that is already set untouched.
+fn apply(entries: impl IntoIterator<Item = (String, String)>) {
+    for (key, value) in entries {
+        if std::env::var_os(&key).is_none() {
+            std::env::set_var(key, value);
+        }
+    }
+}
+
+/// Parse `path` and load its entries into the process environment.
+///
+/// Variables already present in the process environment are never
+/// overwritten, so explicit OS environment always wins over the file.
+pub fn load_from<P: AsRef<Path>>(path: P) -> Result<(), ParseError> {
+    let path = path.as_ref();
+    let contents = read_to_string(path)?;
+    apply(parse_str(&contents)?);
+    Ok(())
+}
+
+/// Load `.env` from the current directory into the process environment.
+///
+/// Returns `Ok(())` if `.env` does not exist; any other I/O error or
+/// parse error is returned.
+pub fn load() -> Result<(), ParseError> {
+    match load_from(".env") {
+        Err(ParseError::Io { source, .. }) if source.kind() == std::io::ErrorKind::NotFound => {
+            Ok(())
+        }
+        other => other,
+    }
+}
+
+/// Builder for layered, profile-aware `.env` loading.
+///
+/// `EnvGrd::profile("production").load()` loads `.env` from `dir` first,
+/// then overlays `.env.production` on top of it, so profile-specific
+/// files can override the shared defaults. As with [`load`], explicit
+/// process environment variables always take precedence over both
+/// files.
+pub struct EnvGrd {
+    dir: PathBuf,
+    profile: Option<String>,
+}
+
+impl EnvGrd {
+    /// Start a loader for the given profile, rooted at the current directory.
+    pub fn profile(name: impl Into<String>) -> Self {
+        EnvGrd {
+            dir: PathBuf::from("."),
+            profile: Some(name.into()),
+        }
+    }
+
+    /// Root the lookup for `.env` / `.env.<profile>` at `dir` instead of
+    /// the current directory.
+    pub fn dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.dir = dir.into();
+        self
+    }
+
+    /// Parse and merge the layered files, then apply the result to the
+    /// process environment.
+    pub fn load(self) -> Result<(), ParseError> {
+        apply(self.merged()?);
+        Ok(())
+    }
+
+    /// Parse and merge the layered files without touching the process
+    /// environment, returning the resulting key/value pairs in
+    /// first-seen order with later layers overriding earlier ones.
+    pub fn merged(&self) -> Result<Vec<(String, String)>, ParseError> {
+        let mut order = Vec::new();
+        let mut values: HashMap<String, String> = HashMap::new();
+
+        let mut layer = |path: PathBuf| -> Result<(), ParseError> {
+            let contents = match read_to_string(&path) {
+                Ok(contents) => contents,
+                Err(ParseError::Io { source, .. })
+                    if source.kind() == std::io::ErrorKind::NotFound =>
+                {
+                    return Ok(())
+                }
+                Err(err) => return Err(err),
+            };
+            for (key, value) in parse_str(&contents)? {
+                if !values.contains_key(&key) {
+                    order.push(key.clone());
+                }
+                values.insert(key, value);
+            }
+            Ok(())
+        };
+
+        layer(self.dir.join(".env"))?;
+        if let Some(profile) = &self.profile {
+            layer(self.dir.join(format!(".env.{profile}")))?;
+        }
+
+        Ok(order
+            .into_iter()
+            .map(|key| {
+                let value = values.remove(&key).expect("key came from values");
+                (key, value)
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_basic_entries() {
+        let entries = parse_str("FOO=bar\nBAZ=qux\n").unwrap();
+        assert_eq!(
+            entries,
+            vec![
+                ("FOO".to_string(), "bar".to_string()),
+                ("BAZ".to_string(), "qux".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn skips_comments_and_blank_lines() {
+        let entries = parse_str("# a comment\n\nFOO=bar\n  \n# trailing\n").unwrap();
+        assert_eq!(entries, vec![("FOO".to_string(), "bar".to_string())]);
+    }
+
+    #[test]
+    fn strips_export_prefix() {
+        let entries = parse_str("export FOO=bar\n").unwrap();
+        assert_eq!(entries, vec![("FOO".to_string(), "bar".to_string())]);
+    }
+
+    #[test]
+    fn handles_quoted_values_and_escapes() {
+        let entries = parse_str("FOO=\"line1\\nline2\"\nBAR='raw\\nvalue'\n").unwrap();
+        assert_eq!(
+            entries,
+            vec![
+                ("FOO".to_string(), "line1\nline2".to_string()),
+                ("BAR".to_string(), "raw\\nvalue".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn tolerates_crlf_and_trailing_whitespace() {
+        let entries = parse_str("FOO=bar  \r\nBAZ=qux\r\n").unwrap();
+        assert_eq!(
+            entries,
+            vec![
+                ("FOO".to_string(), "bar".to_string()),
+                ("BAZ".to_string(), "qux".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn reports_malformed_line_with_number() {
+        let err = parse_str("FOO=bar\nnotakeyvalue\n").unwrap_err();
+        match err {
+            ParseError::MalformedLine { line, .. } => assert_eq!(line, 2),
+            other => panic!("expected MalformedLine, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn reports_unterminated_quote() {
+        let err = parse_str("FOO=\"unterminated\n").unwrap_err();
+        match err {
+            ParseError::UnterminatedQuote { line } => assert_eq!(line, 1),
+            other => panic!("expected UnterminatedQuote, got {other:?}"),
+        }
+    }
+}
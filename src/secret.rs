@@ -0,0 +1,140 @@
+use std::fmt;
+use std::str::FromStr;
+
+const MASK: &str = "****";
+
+/// A value that must never be printed in the clear.
+///
+/// `Debug` and `Display` always render as a fixed mask, so a stray
+/// `println!("{config:?}")` or log line can't leak it. The wrapped value
+/// is reachable only through [`Secret::expose_secret`], which makes every
+/// call site that actually needs the plaintext grep-able.
+///
+/// With the `zeroize` feature enabled, `T` must implement
+/// [`zeroize::Zeroize`] and the wrapped buffer is zeroed on drop.
+#[cfg(not(feature = "zeroize"))]
+pub struct Secret<T>(T);
+
+/// See the non-`zeroize` [`Secret`] doc comment; this variant additionally
+/// requires `T: Zeroize` and zeroes its buffer on drop.
+#[cfg(feature = "zeroize")]
+pub struct Secret<T: zeroize::Zeroize>(T);
+
+#[cfg(not(feature = "zeroize"))]
+impl<T> Secret<T> {
+    /// Wrap `value` as a secret.
+    pub fn new(value: T) -> Self {
+        Secret(value)
+    }
+
+    /// Access the wrapped value.
+    pub fn expose_secret(&self) -> &T {
+        &self.0
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<T: zeroize::Zeroize> Secret<T> {
+    /// Wrap `value` as a secret.
+    pub fn new(value: T) -> Self {
+        Secret(value)
+    }
+
+    /// Access the wrapped value.
+    pub fn expose_secret(&self) -> &T {
+        &self.0
+    }
+}
+
+#[cfg(not(feature = "zeroize"))]
+impl<T> fmt::Debug for Secret<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(MASK)
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<T: zeroize::Zeroize> fmt::Debug for Secret<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(MASK)
+    }
+}
+
+#[cfg(not(feature = "zeroize"))]
+impl<T> fmt::Display for Secret<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(MASK)
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<T: zeroize::Zeroize> fmt::Display for Secret<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(MASK)
+    }
+}
+
+#[cfg(not(feature = "zeroize"))]
+impl<T> From<T> for Secret<T> {
+    fn from(value: T) -> Self {
+        Secret::new(value)
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<T: zeroize::Zeroize> From<T> for Secret<T> {
+    fn from(value: T) -> Self {
+        Secret::new(value)
+    }
+}
+
+/// Parses like the wrapped type, so `Secret<T>` can be used as a
+/// `#[derive(FromEnv)]` field type with no extra plumbing.
+#[cfg(not(feature = "zeroize"))]
+impl<T: FromStr> FromStr for Secret<T> {
+    type Err = T::Err;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        T::from_str(s).map(Secret::new)
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<T: FromStr + zeroize::Zeroize> FromStr for Secret<T> {
+    type Err = T::Err;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        T::from_str(s).map(Secret::new)
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<T: zeroize::Zeroize> Drop for Secret<T> {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn debug_and_display_are_masked() {
+        let secret = Secret::new("hunter2".to_string());
+        assert_eq!(format!("{secret:?}"), "****");
+        assert_eq!(format!("{secret}"), "****");
+    }
+
+    #[test]
+    fn expose_secret_returns_the_value() {
+        let secret = Secret::new("hunter2".to_string());
+        assert_eq!(secret.expose_secret(), "hunter2");
+    }
+
+    #[test]
+    fn parses_via_from_str() {
+        let secret: Secret<u16> = "443".parse().unwrap();
+        assert_eq!(*secret.expose_secret(), 443);
+    }
+}
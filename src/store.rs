@@ -0,0 +1,209 @@
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use crate::error::ParseError;
+use crate::loader::{is_valid_key, read_to_string, unquote};
+
+/// One physical line of a `.env` file as it was read from disk.
+#[derive(Debug, Clone)]
+enum Line {
+    /// A `KEY=VALUE` line. `raw` is the exact text to emit on [`EnvStore::write`]
+    /// (including its line terminator) until the key is reassigned.
+    Entry { key: String, value: String, raw: String },
+    /// A comment or blank line, preserved verbatim.
+    Verbatim(String),
+}
+
+/// An ordered, in-memory `.env` file that can be edited and written back.
+///
+/// Unlike [`load`](crate::load) / [`load_from`](crate::load_from), which
+/// apply a file once to the process environment, `EnvStore` gives tooling
+/// and tests a way to programmatically read, [`set`](EnvStore::set), and
+/// [`remove`](EnvStore::remove) entries and persist the result — rotating
+/// a `SECRET_KEY`, bumping a `DATABASE_URL` — without clobbering
+/// surrounding comments or reordering unrelated keys. Parsing an
+/// unmodified file and calling [`write`](EnvStore::write) reproduces it
+/// byte-for-byte.
+#[derive(Debug, Clone, Default)]
+pub struct EnvStore {
+    lines: Vec<Line>,
+}
+
+impl EnvStore {
+    /// Parse `input` into a store, preserving comments, blank lines, and
+    /// key order.
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut lines = Vec::new();
+        for (idx, raw_line) in split_keep_terminator(input).into_iter().enumerate() {
+            let line_no = idx + 1;
+            let trimmed = raw_line.trim_end_matches(['\n', '\r']).trim();
+            if trimmed.is_empty() || trimmed.starts_with('#') {
+                lines.push(Line::Verbatim(raw_line.to_string()));
+                continue;
+            }
+            let body = trimmed.strip_prefix("export ").unwrap_or(trimmed).trim_start();
+
+            let eq = body.find('=').ok_or_else(|| ParseError::MalformedLine {
+                line: line_no,
+                text: raw_line.to_string(),
+            })?;
+            let key = body[..eq].trim();
+            if key.is_empty() || !is_valid_key(key) {
+                return Err(ParseError::MalformedLine {
+                    line: line_no,
+                    text: raw_line.to_string(),
+                });
+            }
+            let raw_value = body[eq + 1..].trim();
+            let value = unquote(raw_value, line_no)?;
+            lines.push(Line::Entry {
+                key: key.to_string(),
+                value,
+                raw: raw_line.to_string(),
+            });
+        }
+        Ok(EnvStore { lines })
+    }
+
+    /// Parse the `.env`-style file at `path` into a store.
+    pub fn load_from<P: AsRef<Path>>(path: P) -> Result<Self, ParseError> {
+        Self::parse(&read_to_string(path.as_ref())?)
+    }
+
+    /// Look up the current value of `key`.
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.lines.iter().find_map(|line| match line {
+            Line::Entry { key: k, value, .. } if k == key => Some(value.as_str()),
+            _ => None,
+        })
+    }
+
+    /// Set `key` to `value`, updating it in place if already present or
+    /// appending a new entry at the end of the file otherwise.
+    pub fn set(&mut self, key: impl Into<String>, value: impl Into<String>) {
+        let key = key.into();
+        let value = value.into();
+        let raw = format_entry(&key, &value);
+        for line in &mut self.lines {
+            if let Line::Entry { key: k, value: v, raw: r } = line {
+                if *k == key {
+                    *v = value;
+                    *r = raw;
+                    return;
+                }
+            }
+        }
+        if let Some(Line::Entry { raw: last, .. } | Line::Verbatim(last)) = self.lines.last_mut() {
+            if !last.ends_with('\n') {
+                last.push('\n');
+            }
+        }
+        self.lines.push(Line::Entry { key, value, raw });
+    }
+
+    /// Remove `key`, returning its value if it was present.
+    ///
+    /// Surrounding comments and other keys keep their original order and
+    /// formatting.
+    pub fn remove(&mut self, key: &str) -> Option<String> {
+        let position = self.lines.iter().position(|line| match line {
+            Line::Entry { key: k, .. } => k == key,
+            Line::Verbatim(_) => false,
+        })?;
+        match self.lines.remove(position) {
+            Line::Entry { value, .. } => Some(value),
+            Line::Verbatim(_) => unreachable!("position only matches Entry lines"),
+        }
+    }
+
+    /// Render the store back into `.env` file contents.
+    pub fn write(&self) -> String {
+        self.lines
+            .iter()
+            .map(|line| match line {
+                Line::Entry { raw, .. } => raw.as_str(),
+                Line::Verbatim(raw) => raw.as_str(),
+            })
+            .collect()
+    }
+
+    /// Render and write the store to `path`.
+    pub fn write_to<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        fs::write(path, self.write())
+    }
+}
+
+/// Format a `KEY=VALUE` line, quoting the value if it would otherwise be
+/// ambiguous to reparse (empty, or containing leading/trailing
+/// whitespace, a `#`, or a newline).
+fn format_entry(key: &str, value: &str) -> String {
+    let needs_quotes = value.is_empty()
+        || value.trim() != value
+        || value.contains('#')
+        || value.contains('\n');
+    if needs_quotes {
+        let escaped = value.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n");
+        format!("{key}=\"{escaped}\"\n")
+    } else {
+        format!("{key}={value}\n")
+    }
+}
+
+/// Split `input` into lines, each including its own trailing line
+/// terminator (`\n` or `\r\n`), so the original formatting can be
+/// reproduced exactly. The final line keeps no terminator if the input
+/// doesn't end with one.
+fn split_keep_terminator(input: &str) -> Vec<&str> {
+    let mut lines = Vec::new();
+    let mut start = 0;
+    for (i, _) in input.match_indices('\n') {
+        lines.push(&input[start..=i]);
+        start = i + 1;
+    }
+    if start < input.len() {
+        lines.push(&input[start..]);
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_unmodified_file_byte_for_byte() {
+        let original = "# config\nFOO=bar\n\nBAZ=\"q u x\"\nexport QUUX=1\r\n";
+        let store = EnvStore::parse(original).unwrap();
+        assert_eq!(store.write(), original);
+    }
+
+    #[test]
+    fn get_reads_current_values() {
+        let store = EnvStore::parse("FOO=bar\nBAZ=qux\n").unwrap();
+        assert_eq!(store.get("FOO"), Some("bar"));
+        assert_eq!(store.get("MISSING"), None);
+    }
+
+    #[test]
+    fn set_updates_in_place_without_disturbing_other_lines() {
+        let mut store = EnvStore::parse("# note\nFOO=bar\nBAZ=qux\n").unwrap();
+        store.set("FOO", "new-value");
+        assert_eq!(store.get("FOO"), Some("new-value"));
+        assert_eq!(store.write(), "# note\nFOO=new-value\nBAZ=qux\n");
+    }
+
+    #[test]
+    fn set_appends_new_keys() {
+        let mut store = EnvStore::parse("FOO=bar\n").unwrap();
+        store.set("BAZ", "qux");
+        assert_eq!(store.write(), "FOO=bar\nBAZ=qux\n");
+    }
+
+    #[test]
+    fn remove_deletes_only_the_matching_entry() {
+        let mut store = EnvStore::parse("# note\nFOO=bar\nBAZ=qux\n").unwrap();
+        assert_eq!(store.remove("FOO"), Some("bar".to_string()));
+        assert_eq!(store.write(), "# note\nBAZ=qux\n");
+    }
+}
@@ -0,0 +1,64 @@
+use std::env;
+use std::sync::Mutex;
+
+use envgrd::{FieldErrorKind, FromEnv};
+
+// Tests mutate process-wide environment variables, so they must not run
+// concurrently with each other.
+static ENV_LOCK: Mutex<()> = Mutex::new(());
+
+#[derive(Debug, PartialEq, Eq, FromEnv)]
+struct Config {
+    #[env(rename = "DATABASE_URL")]
+    database_url: String,
+    #[env(default = "8080")]
+    port: u16,
+    api_key: Option<String>,
+}
+
+#[test]
+fn binds_renamed_defaulted_and_optional_fields() {
+    let _guard = ENV_LOCK.lock().unwrap();
+    env::remove_var("DATABASE_URL");
+    env::remove_var("PORT");
+    env::remove_var("API_KEY");
+
+    env::set_var("DATABASE_URL", "postgres://localhost/app");
+
+    let config = Config::from_env().unwrap();
+    assert_eq!(
+        config,
+        Config {
+            database_url: "postgres://localhost/app".to_string(),
+            port: 8080,
+            api_key: None,
+        }
+    );
+
+    env::remove_var("DATABASE_URL");
+}
+
+#[test]
+fn collects_all_missing_and_invalid_fields_at_once() {
+    let _guard = ENV_LOCK.lock().unwrap();
+    env::remove_var("DATABASE_URL");
+    env::set_var("PORT", "not-a-number");
+    env::remove_var("API_KEY");
+
+    let err = Config::from_env().unwrap_err();
+    let kinds: Vec<_> = err
+        .errors()
+        .iter()
+        .map(|field| (field.field, field.kind.clone()))
+        .collect();
+
+    assert_eq!(kinds.len(), 2);
+    assert!(kinds
+        .iter()
+        .any(|(name, kind)| *name == "database_url" && *kind == FieldErrorKind::Missing));
+    assert!(kinds
+        .iter()
+        .any(|(name, kind)| *name == "port" && matches!(kind, FieldErrorKind::Invalid(_))));
+
+    env::remove_var("PORT");
+}
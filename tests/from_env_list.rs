@@ -0,0 +1,57 @@
+use std::env;
+use std::sync::Mutex;
+
+use envgrd::FromEnv;
+
+static ENV_LOCK: Mutex<()> = Mutex::new(());
+
+#[derive(Debug, PartialEq, Eq, FromEnv)]
+struct Config {
+    #[env(delimiter = ",")]
+    hosts: Vec<String>,
+    #[env(delimiter = ",")]
+    ports: Vec<u16>,
+}
+
+#[test]
+fn splits_delimited_values_into_vecs() {
+    let _guard = ENV_LOCK.lock().unwrap();
+    env::set_var("HOSTS", "a, b ,c");
+    env::set_var("PORTS", "80,443");
+
+    let config = Config::from_env().unwrap();
+    assert_eq!(
+        config,
+        Config {
+            hosts: vec!["a".to_string(), "b".to_string(), "c".to_string()],
+            ports: vec![80, 443],
+        }
+    );
+
+    env::remove_var("HOSTS");
+    env::remove_var("PORTS");
+}
+
+#[test]
+fn reports_index_and_token_for_bad_element() {
+    let _guard = ENV_LOCK.lock().unwrap();
+    env::set_var("HOSTS", "a,b");
+    env::set_var("PORTS", "80,bogus");
+
+    let err = Config::from_env().unwrap_err();
+    let port_error = err
+        .errors()
+        .iter()
+        .find(|field| field.field == "ports")
+        .expect("ports field error");
+    match &port_error.kind {
+        envgrd::FieldErrorKind::Invalid(message) => {
+            assert!(message.contains("element 1"));
+            assert!(message.contains("bogus"));
+        }
+        other => panic!("expected Invalid, got {other:?}"),
+    }
+
+    env::remove_var("HOSTS");
+    env::remove_var("PORTS");
+}
@@ -0,0 +1,28 @@
+use std::env;
+use std::sync::Mutex;
+
+use envgrd::{FromEnv, Secret};
+
+static ENV_LOCK: Mutex<()> = Mutex::new(());
+
+#[derive(Debug, FromEnv)]
+struct Config {
+    #[env(secret)]
+    api_key: Secret<String>,
+    #[env(secret)]
+    port: Option<Secret<u16>>,
+}
+
+#[test]
+fn secret_fields_parse_but_stay_masked() {
+    let _guard = ENV_LOCK.lock().unwrap();
+    env::set_var("API_KEY", "topsecret");
+    env::remove_var("PORT");
+
+    let config = Config::from_env().unwrap();
+    assert_eq!(config.api_key.expose_secret(), "topsecret");
+    assert!(config.port.is_none());
+    assert!(!format!("{config:?}").contains("topsecret"));
+
+    env::remove_var("API_KEY");
+}
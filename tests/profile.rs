@@ -0,0 +1,39 @@
+use std::fs;
+
+use envgrd::EnvGrd;
+
+#[test]
+fn profile_overlays_base_env() {
+    let dir = tempfile::tempdir().unwrap();
+    fs::write(dir.path().join(".env"), "SHARED=base\nFROM_BASE=1\n").unwrap();
+    fs::write(dir.path().join(".env.production"), "SHARED=prod\n").unwrap();
+
+    let merged = EnvGrd::profile("production")
+        .dir(dir.path())
+        .merged()
+        .unwrap();
+
+    assert_eq!(
+        merged
+            .iter()
+            .find(|(k, _)| k == "SHARED")
+            .map(|(_, v)| v.as_str()),
+        Some("prod")
+    );
+    assert_eq!(
+        merged
+            .iter()
+            .find(|(k, _)| k == "FROM_BASE")
+            .map(|(_, v)| v.as_str()),
+        Some("1")
+    );
+}
+
+#[test]
+fn missing_profile_file_is_not_an_error() {
+    let dir = tempfile::tempdir().unwrap();
+    fs::write(dir.path().join(".env"), "SHARED=base\n").unwrap();
+
+    let merged = EnvGrd::profile("staging").dir(dir.path()).merged().unwrap();
+    assert_eq!(merged, vec![("SHARED".to_string(), "base".to_string())]);
+}
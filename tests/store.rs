@@ -0,0 +1,32 @@
+use std::fs;
+
+use envgrd::EnvStore;
+
+#[test]
+fn round_trips_a_file_on_disk_unmodified() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join(".env");
+    let original = "# shared defaults\nAPI_KEY=abc123\n\nDATABASE_URL=\"postgres://localhost\"\n";
+    fs::write(&path, original).unwrap();
+
+    let store = EnvStore::load_from(&path).unwrap();
+    store.write_to(&path).unwrap();
+
+    assert_eq!(fs::read_to_string(&path).unwrap(), original);
+}
+
+#[test]
+fn edits_persist_across_a_write_load_cycle() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join(".env");
+    fs::write(&path, "# rotate me\nSECRET_KEY=old\nDATABASE_URL=postgres://localhost\n").unwrap();
+
+    let mut store = EnvStore::load_from(&path).unwrap();
+    store.set("SECRET_KEY", "new");
+    store.write_to(&path).unwrap();
+
+    let reloaded = EnvStore::load_from(&path).unwrap();
+    assert_eq!(reloaded.get("SECRET_KEY"), Some("new"));
+    assert_eq!(reloaded.get("DATABASE_URL"), Some("postgres://localhost"));
+    assert!(fs::read_to_string(&path).unwrap().starts_with("# rotate me\n"));
+}